@@ -12,6 +12,11 @@ pub enum AuthError {
     AdminAlreadyExists,
     AdminNotFound,
     LastAdmin,
+    RequestNotFound,
+    RequestExpired,
+    InvalidSignature,
+    ImportFailed,
+    AccountStillActive,
 }
 
 impl FunctionError for AuthError {
@@ -24,6 +29,11 @@ impl FunctionError for AuthError {
             AuthError::AdminAlreadyExists => "Admin already exists",
             AuthError::AdminNotFound => "Admin not found",
             AuthError::LastAdmin => "Cannot remove the last admin",
+            AuthError::RequestNotFound => "Action request not found",
+            AuthError::RequestExpired => "Action request has expired",
+            AuthError::InvalidSignature => "Signature failed verification",
+            AuthError::ImportFailed => "Failed to decode imported state",
+            AuthError::AccountStillActive => "Account is still within the inactivity window",
         })
     }
 }
\ No newline at end of file
@@ -1,9 +1,43 @@
 use near_sdk::{near, AccountId};
+use crate::types::PendingAction;
 
 #[near(event_json(standard = "nep297"))]
 pub enum AuthEvent {
     #[event_version("1.0.0")]
     KeyRegistered { account_id: AccountId, public_key: String },
+    #[event_version("2.0.0")]
+    KeyRemoved {
+        account_id: AccountId,
+        public_key: String,
+        expiration_timestamp: Option<u64>,
+        is_multi_sig: bool,
+        multi_sig_threshold: Option<u32>,
+    },
     #[event_version("1.0.0")]
-    KeyRemoved { account_id: AccountId, public_key: String },
+    KeyRotated {
+        account_id: AccountId,
+        old_public_key: String,
+        new_public_key: String,
+        expiration_timestamp: Option<u64>,
+        is_multi_sig: bool,
+        multi_sig_threshold: Option<u32>,
+    },
+    #[event_version("1.0.0")]
+    AccountDeregistered {
+        account_id: AccountId,
+        last_active_timestamp: u64,
+        removed_key_count: u32,
+    },
+    #[event_version("1.0.0")]
+    ExpiredKeysPurged { account_id: AccountId, removed_key_count: u32 },
+    #[event_version("1.0.0")]
+    AdminAdded { account_id: AccountId },
+    #[event_version("1.0.0")]
+    AdminRemoved { account_id: AccountId },
+    #[event_version("1.0.0")]
+    ContractPaused { by: AccountId },
+    #[event_version("1.0.0")]
+    ContractUnpaused { by: AccountId },
+    #[event_version("1.0.0")]
+    ActionProposed { request_id: u64, account_id: AccountId, action: PendingAction },
 }
\ No newline at end of file
@@ -1,6 +1,6 @@
 use near_sdk::{near, env, AccountId, PublicKey, PanicOnDefault};
 use crate::state::AuthContractState;
-use crate::types::KeyInfo;
+use crate::types::{ActionRequest, ExportChunk, KeyInfo, PendingAction};
 use crate::errors::AuthError;
 
 mod state;
@@ -19,17 +19,57 @@ impl AuthContract {
     #[init]
     pub fn new() -> Self {
         Self {
-            state: AuthContractState::new(),
+            state: AuthContractState::new(env::predecessor_account_id()),
         }
     }
 
+    #[handle_result]
+    pub fn add_admin(&mut self, account_id: AccountId) -> Result<(), AuthError> {
+        self.state.add_admin(&env::predecessor_account_id(), account_id)
+    }
+
+    #[handle_result]
+    pub fn remove_admin(&mut self, account_id: AccountId) -> Result<(), AuthError> {
+        self.state.remove_admin(&env::predecessor_account_id(), account_id)
+    }
+
+    #[handle_result]
+    pub fn pause(&mut self) -> Result<(), AuthError> {
+        self.state.pause(&env::predecessor_account_id())
+    }
+
+    #[handle_result]
+    pub fn unpause(&mut self) -> Result<(), AuthError> {
+        self.state.unpause(&env::predecessor_account_id())
+    }
+
     pub fn is_authorized(
         &mut self,
         account_id: AccountId,
         public_key: PublicKey,
         signatures: Option<Vec<Vec<u8>>>,
+        action: String,
     ) -> bool {
-        self.state.is_authorized(&account_id, &public_key, signatures)
+        self.state.is_authorized(&account_id, &public_key, signatures, action)
+    }
+
+    #[handle_result]
+    pub fn propose_action(
+        &mut self,
+        account_id: AccountId,
+        action: PendingAction,
+    ) -> Result<u64, AuthError> {
+        self.state.propose_action(&env::predecessor_account_id(), &account_id, action)
+    }
+
+    #[handle_result]
+    pub fn approve_action(
+        &mut self,
+        request_id: u64,
+        public_key: PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, AuthError> {
+        self.state.approve_action(request_id, public_key, signature)
     }
 
     #[handle_result]
@@ -60,6 +100,27 @@ impl AuthContract {
         self.state.remove_key(&env::predecessor_account_id(), &account_id, public_key)
     }
 
+    #[handle_result]
+    pub fn rotate_key(
+        &mut self,
+        account_id: AccountId,
+        old_public_key: PublicKey,
+        new_public_key: PublicKey,
+        expiration_days: Option<u32>,
+        is_multi_sig: bool,
+        multi_sig_threshold: Option<u32>,
+    ) -> Result<(), AuthError> {
+        self.state.rotate_key(
+            &env::predecessor_account_id(),
+            &account_id,
+            old_public_key,
+            new_public_key,
+            expiration_days,
+            is_multi_sig,
+            multi_sig_threshold,
+        )
+    }
+
     #[handle_result]
     pub fn remove_expired_keys(&mut self, account_id: AccountId) -> Result<(), AuthError> {
         self.state.remove_expired_keys(&account_id)
@@ -77,4 +138,21 @@ impl AuthContract {
     pub fn get_key_info(&self, account_id: AccountId, public_key: PublicKey) -> Option<KeyInfo> {
         self.state.get_key_info(&account_id, &public_key)
     }
+
+    pub fn get_accounts_by_key(&self, public_key: PublicKey, limit: u32, offset: u32) -> Vec<AccountId> {
+        self.state.get_accounts_by_key(&public_key, limit, offset)
+    }
+
+    pub fn get_action_request(&self, request_id: u64) -> Option<ActionRequest> {
+        self.state.get_action_request(request_id)
+    }
+
+    pub fn export_state(&self, limit: u32, offset: u32, compress: bool) -> ExportChunk {
+        self.state.export_state(limit, offset, compress)
+    }
+
+    #[handle_result]
+    pub fn import_state(&mut self, chunk: ExportChunk) -> Result<(), AuthError> {
+        self.state.import_state(&env::predecessor_account_id(), chunk)
+    }
 }
\ No newline at end of file
@@ -1,10 +1,13 @@
 use near_sdk::{env, AccountId, PublicKey, BorshStorageKey};
 use near_sdk::store::{LookupMap, IterableSet};
-use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
-use crate::types::KeyInfo;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use crate::types::{AccountSnapshot, ActionRequest, ExportChunk, KeyInfo, PendingAction};
 use crate::errors::AuthError;
 use crate::events::AuthEvent;
 
+/// How long a pending `ActionRequest` stays open for approval before it expires.
+const REQUEST_EXPIRY_MS: u64 = 24 * 60 * 60 * 1000;
+
 #[derive(BorshSerialize, BorshDeserialize, BorshStorageKey)]
 #[borsh(crate = "near_sdk::borsh")]
 enum StorageKey {
@@ -12,6 +15,13 @@ enum StorageKey {
     KeySet { account_id: AccountId },
     LastActive,
     Accounts,
+    Nonces,
+    Requests,
+    RequestApprovalsMap,
+    RequestApprovals { request_id: u64 },
+    Admins,
+    KeyIndex,
+    KeyIndexSet { public_key: PublicKey },
 }
 
 #[derive(BorshSerialize, BorshDeserialize, near_sdk_macros::NearSchema)]
@@ -21,15 +31,131 @@ pub struct AuthContractState {
     pub keys: LookupMap<AccountId, IterableSet<KeyInfo>>,
     pub last_active_timestamps: LookupMap<AccountId, u64>,
     pub registered_accounts: IterableSet<AccountId>,
+    pub nonces: LookupMap<AccountId, u64>,
+    pub action_requests: LookupMap<u64, ActionRequest>,
+    pub request_approvals: LookupMap<u64, IterableSet<PublicKey>>,
+    pub next_request_id: u64,
+    pub admins: IterableSet<AccountId>,
+    pub paused: bool,
+    pub key_index: LookupMap<PublicKey, IterableSet<AccountId>>,
+}
+
+/// Verifies an ed25519 signature over `message` against a NEAR `PublicKey`.
+///
+/// Returns `false` (rather than panicking) for non-ed25519 keys or malformed
+/// signature bytes, since callers pass attacker-controlled data here.
+fn verify_ed25519(public_key: &PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    let key_bytes = public_key.as_bytes();
+    if key_bytes.len() != 33 || key_bytes[0] != 0 {
+        return false;
+    }
+    let key: [u8; 32] = match key_bytes[1..].try_into() {
+        Ok(key) => key,
+        Err(_) => return false,
+    };
+    let signature: [u8; 64] = match signature.try_into() {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    env::ed25519_verify(&signature, message, &key)
 }
 
 impl AuthContractState {
-    pub fn new() -> Self {
+    pub fn new(deployer: AccountId) -> Self {
+        let mut admins = IterableSet::new(StorageKey::Admins);
+        admins.insert(deployer);
+
         Self {
             keys: LookupMap::new(StorageKey::Keys),
             last_active_timestamps: LookupMap::new(StorageKey::LastActive),
             registered_accounts: IterableSet::new(StorageKey::Accounts),
+            nonces: LookupMap::new(StorageKey::Nonces),
+            action_requests: LookupMap::new(StorageKey::Requests),
+            request_approvals: LookupMap::new(StorageKey::RequestApprovalsMap),
+            next_request_id: 0,
+            admins,
+            paused: false,
+            key_index: LookupMap::new(StorageKey::KeyIndex),
+        }
+    }
+
+    /// Adds `account_id` to the set of accounts indexed under `public_key`,
+    /// creating the set on first use.
+    fn index_key(&mut self, public_key: &PublicKey, account_id: &AccountId) {
+        if self.key_index.get(public_key).is_none() {
+            self.key_index.insert(public_key.clone(), IterableSet::new(StorageKey::KeyIndexSet {
+                public_key: public_key.clone(),
+            }));
+        }
+        let accounts = self.key_index.get_mut(public_key).expect("key index set should exist");
+        accounts.insert(account_id.clone());
+    }
+
+    /// Removes `account_id` from the set indexed under `public_key`, dropping
+    /// the set entirely once it's empty so the index never accumulates dead entries.
+    fn deindex_key(&mut self, public_key: &PublicKey, account_id: &AccountId) {
+        if let Some(accounts) = self.key_index.get_mut(public_key) {
+            accounts.remove(account_id);
+            if accounts.is_empty() {
+                self.key_index.remove(public_key);
+            }
+        }
+    }
+
+    fn bump_nonce(&mut self, account_id: &AccountId) {
+        let next = self.nonces.get(account_id).copied().unwrap_or(0) + 1;
+        self.nonces.insert(account_id.clone(), next);
+    }
+
+    fn require_admin(&self, caller: &AccountId) -> Result<(), AuthError> {
+        if !self.admins.contains(caller) {
+            return Err(AuthError::Unauthorized);
+        }
+        Ok(())
+    }
+
+    fn require_not_paused(&self) -> Result<(), AuthError> {
+        if self.paused {
+            return Err(AuthError::ContractPaused);
+        }
+        Ok(())
+    }
+
+    pub fn add_admin(&mut self, caller: &AccountId, new_admin: AccountId) -> Result<(), AuthError> {
+        self.require_admin(caller)?;
+        if self.admins.contains(&new_admin) {
+            return Err(AuthError::AdminAlreadyExists);
         }
+        self.admins.insert(new_admin.clone());
+        AuthEvent::AdminAdded { account_id: new_admin }.emit();
+        Ok(())
+    }
+
+    pub fn remove_admin(&mut self, caller: &AccountId, admin: AccountId) -> Result<(), AuthError> {
+        self.require_admin(caller)?;
+        if !self.admins.contains(&admin) {
+            return Err(AuthError::AdminNotFound);
+        }
+        if self.admins.len() <= 1 {
+            return Err(AuthError::LastAdmin);
+        }
+        self.admins.remove(&admin);
+        AuthEvent::AdminRemoved { account_id: admin }.emit();
+        Ok(())
+    }
+
+    pub fn pause(&mut self, caller: &AccountId) -> Result<(), AuthError> {
+        self.require_admin(caller)?;
+        self.paused = true;
+        AuthEvent::ContractPaused { by: caller.clone() }.emit();
+        Ok(())
+    }
+
+    pub fn unpause(&mut self, caller: &AccountId) -> Result<(), AuthError> {
+        self.require_admin(caller)?;
+        self.paused = false;
+        AuthEvent::ContractUnpaused { by: caller.clone() }.emit();
+        Ok(())
     }
 
     pub fn is_authorized(
@@ -37,6 +163,7 @@ impl AuthContractState {
         account_id: &AccountId,
         public_key: &PublicKey,
         signatures: Option<Vec<Vec<u8>>>,
+        action: String,
     ) -> bool {
         let key_set = match self.keys.get(account_id) {
             Some(set) => set,
@@ -54,21 +181,147 @@ impl AuthContractState {
             }
         }
 
-        let authorized = if key_info.is_multi_sig {
+        let is_multi_sig = key_info.is_multi_sig;
+        let authorized = if is_multi_sig {
             let threshold = key_info.multi_sig_threshold.unwrap_or(1);
-            let signatures = signatures.unwrap_or_default();
-            signatures.len() as u32 >= threshold
+            let nonce = self.nonces.get(account_id).copied().unwrap_or(0);
+            let message = borsh::to_vec(&(account_id, nonce, &action))
+                .expect("challenge message should serialize");
+
+            let mut distinct_signers: Vec<PublicKey> = Vec::new();
+            for signature in signatures.unwrap_or_default() {
+                for candidate in key_set.iter() {
+                    if distinct_signers.contains(&candidate.public_key) {
+                        continue;
+                    }
+                    if verify_ed25519(&candidate.public_key, &message, &signature) {
+                        distinct_signers.push(candidate.public_key.clone());
+                        break;
+                    }
+                }
+            }
+
+            distinct_signers.len() as u32 >= threshold
         } else {
             true
         };
 
         if authorized {
             self.last_active_timestamps.insert(account_id.clone(), env::block_timestamp_ms());
+            if is_multi_sig {
+                self.bump_nonce(account_id);
+            }
         }
 
         authorized
     }
 
+    /// Submits a sensitive `action` for `account_id` as a pending request that
+    /// auto-executes once enough distinct registered keys approve it.
+    pub fn propose_action(
+        &mut self,
+        caller: &AccountId,
+        account_id: &AccountId,
+        action: PendingAction,
+    ) -> Result<u64, AuthError> {
+        if caller != account_id {
+            return Err(AuthError::Unauthorized);
+        }
+        if self.keys.get(account_id).is_none() {
+            return Err(AuthError::KeyNotFound);
+        }
+
+        let nonce = self.nonces.get(account_id).copied().unwrap_or(0);
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        self.action_requests.insert(request_id, ActionRequest {
+            account_id: account_id.clone(),
+            action: action.clone(),
+            nonce,
+            created_at: env::block_timestamp_ms(),
+        });
+        self.request_approvals.insert(
+            request_id,
+            IterableSet::new(StorageKey::RequestApprovals { request_id }),
+        );
+
+        AuthEvent::ActionProposed { request_id, account_id: account_id.clone(), action }.emit();
+
+        Ok(request_id)
+    }
+
+    /// Records `public_key`'s approval of a pending action request, verifying
+    /// `signature` over the request's replay-protected challenge. Once the
+    /// targeted key's multisig threshold (1 if it has none) of distinct
+    /// approvers is reached, the action executes and the request is purged.
+    pub fn approve_action(
+        &mut self,
+        request_id: u64,
+        public_key: PublicKey,
+        signature: Vec<u8>,
+    ) -> Result<bool, AuthError> {
+        let request = self.action_requests.get(&request_id).ok_or(AuthError::RequestNotFound)?;
+        let account_id = request.account_id.clone();
+
+        if env::block_timestamp_ms() > request.created_at + REQUEST_EXPIRY_MS {
+            self.action_requests.remove(&request_id);
+            self.request_approvals.remove(&request_id);
+            self.bump_nonce(&account_id);
+            return Err(AuthError::RequestExpired);
+        }
+
+        let key_set = self.keys.get(&account_id).ok_or(AuthError::KeyNotFound)?;
+        key_set.iter().find(|k| k.public_key == public_key).ok_or(AuthError::Unauthorized)?;
+
+        // The threshold is derived from the key the action actually targets,
+        // not from whichever key happens to be approving — otherwise any
+        // single non-multisig key on the account could approve alone.
+        let target_key = match &request.action {
+            PendingAction::RotateKey { old_public_key, .. } => old_public_key,
+            PendingAction::RemoveKey { public_key: target } => target,
+        };
+        let threshold = key_set
+            .iter()
+            .find(|k| k.public_key == *target_key)
+            .and_then(|k| k.multi_sig_threshold)
+            .unwrap_or(1);
+
+        let message = borsh::to_vec(&(&account_id, request.nonce, request_id))
+            .expect("challenge message should serialize");
+        let action = request.action.clone();
+        if !verify_ed25519(&public_key, &message, &signature) {
+            return Err(AuthError::InvalidSignature);
+        }
+
+        let approvers = self.request_approvals.get_mut(&request_id)
+            .expect("approvals set should exist for a pending request");
+        approvers.insert(public_key);
+        let approver_count = approvers.len() as u32;
+
+        if approver_count < threshold {
+            return Ok(false);
+        }
+
+        // Execute before purging the request/approvals so a transient failure
+        // (e.g. the contract being paused) leaves the approved request intact
+        // for a retry, instead of silently discarding it.
+        match action {
+            PendingAction::RotateKey { old_public_key, new_public_key, expiration_days, is_multi_sig, multi_sig_threshold } => {
+                self.rotate_key(&account_id, &account_id, old_public_key, new_public_key, expiration_days, is_multi_sig, multi_sig_threshold)?;
+            }
+            PendingAction::RemoveKey { public_key } => {
+                self.remove_key(&account_id, &account_id, public_key)?;
+            }
+        }
+
+        self.action_requests.remove(&request_id);
+        self.request_approvals.remove(&request_id);
+        self.bump_nonce(&account_id);
+
+        Ok(true)
+    }
+
     pub fn register_key(
         &mut self,
         caller: &AccountId,
@@ -78,6 +331,7 @@ impl AuthContractState {
         is_multi_sig: bool,
         multi_sig_threshold: Option<u32>,
     ) -> Result<(), AuthError> {
+        self.require_not_paused()?;
         if caller != account_id {
             return Err(AuthError::Unauthorized);
         }
@@ -105,6 +359,7 @@ impl AuthContractState {
             return Err(AuthError::KeyAlreadyExists);
         }
         key_set.insert(key_info);
+        self.index_key(&public_key, account_id);
 
         self.last_active_timestamps.insert(account_id.clone(), env::block_timestamp_ms());
 
@@ -122,32 +377,44 @@ impl AuthContractState {
         account_id: &AccountId,
         public_key: PublicKey,
     ) -> Result<(), AuthError> {
+        self.require_not_paused()?;
         if caller != account_id {
             return Err(AuthError::Unauthorized);
         }
 
         let key_set = self.keys.get_mut(account_id).ok_or(AuthError::KeyNotFound)?;
-        let key_info = KeyInfo {
-            public_key: public_key.clone(),
-            expiration_timestamp: None,
-            is_multi_sig: false,
-            multi_sig_threshold: None,
-        };
-        if !key_set.remove(&key_info) {
+        let stored_key_info = key_set.iter()
+            .find(|k| k.public_key == public_key)
+            .cloned()
+            .ok_or(AuthError::KeyNotFound)?;
+        if !key_set.remove(&stored_key_info) {
             return Err(AuthError::KeyNotFound);
         }
+        let is_empty = key_set.is_empty();
 
-        if key_set.is_empty() {
-            self.keys.remove(account_id);
-            self.last_active_timestamps.remove(account_id);
-            self.registered_accounts.remove(account_id);
-        }
+        self.deindex_key(&public_key, account_id);
 
         AuthEvent::KeyRemoved {
             account_id: account_id.clone(),
             public_key: format!("{:?}", public_key),
+            expiration_timestamp: stored_key_info.expiration_timestamp,
+            is_multi_sig: stored_key_info.is_multi_sig,
+            multi_sig_threshold: stored_key_info.multi_sig_threshold,
         }.emit();
 
+        if is_empty {
+            let last_active_timestamp = self.last_active_timestamps.get(account_id).copied().unwrap_or(0);
+            self.keys.remove(account_id);
+            self.last_active_timestamps.remove(account_id);
+            self.registered_accounts.remove(account_id);
+
+            AuthEvent::AccountDeregistered {
+                account_id: account_id.clone(),
+                last_active_timestamp,
+                removed_key_count: 1,
+            }.emit();
+        }
+
         Ok(())
     }
 
@@ -161,20 +428,16 @@ impl AuthContractState {
         is_multi_sig: bool,
         multi_sig_threshold: Option<u32>,
     ) -> Result<(), AuthError> {
+        self.require_not_paused()?;
         if caller != account_id {
             return Err(AuthError::Unauthorized);
         }
 
         let key_set = self.keys.get_mut(account_id).ok_or(AuthError::KeyNotFound)?;
-        let old_key_info = KeyInfo {
-            public_key: old_public_key.clone(),
-            expiration_timestamp: None,
-            is_multi_sig: false,
-            multi_sig_threshold: None,
-        };
-        if !key_set.contains(&old_key_info) {
-            return Err(AuthError::KeyNotFound);
-        }
+        let stored_old_key_info = key_set.iter()
+            .find(|k| k.public_key == old_public_key)
+            .cloned()
+            .ok_or(AuthError::KeyNotFound)?;
 
         let new_key_info = KeyInfo {
             public_key: new_public_key.clone(),
@@ -188,20 +451,26 @@ impl AuthContractState {
             return Err(AuthError::KeyAlreadyExists);
         }
 
-        key_set.remove(&old_key_info);
+        key_set.remove(&stored_old_key_info);
         key_set.insert(new_key_info);
+        self.deindex_key(&old_public_key, account_id);
+        self.index_key(&new_public_key, account_id);
         self.last_active_timestamps.insert(account_id.clone(), env::block_timestamp_ms());
 
         AuthEvent::KeyRotated {
             account_id: account_id.clone(),
             old_public_key: format!("{:?}", old_public_key),
             new_public_key: format!("{:?}", new_public_key),
+            expiration_timestamp: stored_old_key_info.expiration_timestamp,
+            is_multi_sig: stored_old_key_info.is_multi_sig,
+            multi_sig_threshold: stored_old_key_info.multi_sig_threshold,
         }.emit();
 
         Ok(())
     }
 
     pub fn remove_expired_keys(&mut self, account_id: &AccountId) -> Result<(), AuthError> {
+        self.require_not_paused()?;
         let key_set = self.keys.get_mut(account_id).ok_or(AuthError::KeyNotFound)?;
         let current_timestamp = env::block_timestamp_ms();
         let mut to_remove = Vec::new();
@@ -212,24 +481,50 @@ impl AuthContractState {
             }
         }
 
+        let removed_count = to_remove.len() as u32;
+        let mut removed_keys = Vec::new();
         for key_info in to_remove {
             key_set.remove(&key_info);
+            removed_keys.push(key_info.public_key.clone());
             AuthEvent::KeyRemoved {
                 account_id: account_id.clone(),
                 public_key: format!("{:?}", key_info.public_key),
+                expiration_timestamp: key_info.expiration_timestamp,
+                is_multi_sig: key_info.is_multi_sig,
+                multi_sig_threshold: key_info.multi_sig_threshold,
+            }.emit();
+        }
+        let is_empty = key_set.is_empty();
+
+        for public_key in removed_keys {
+            self.deindex_key(&public_key, account_id);
+        }
+
+        if removed_count > 0 {
+            AuthEvent::ExpiredKeysPurged {
+                account_id: account_id.clone(),
+                removed_key_count: removed_count,
             }.emit();
         }
 
-        if key_set.is_empty() {
+        if is_empty {
+            let last_active_timestamp = self.last_active_timestamps.get(account_id).copied().unwrap_or(0);
             self.keys.remove(account_id);
             self.last_active_timestamps.remove(account_id);
             self.registered_accounts.remove(account_id);
+
+            AuthEvent::AccountDeregistered {
+                account_id: account_id.clone(),
+                last_active_timestamp,
+                removed_key_count: removed_count,
+            }.emit();
         }
 
         Ok(())
     }
 
     pub fn remove_inactive_accounts(&mut self, account_id: AccountId) -> Result<(), AuthError> {
+        self.require_not_paused()?;
         let last_active = self.last_active_timestamps.get(&account_id).ok_or(AuthError::KeyNotFound)?;
         let current_timestamp = env::block_timestamp_ms();
         const ONE_YEAR_MS: u64 = 31_536_000_000; // 1 year in milliseconds
@@ -237,21 +532,37 @@ impl AuthContractState {
         if current_timestamp <= last_active + ONE_YEAR_MS {
             return Err(AuthError::AccountStillActive);
         }
+        let last_active_timestamp = *last_active;
 
         let key_set = self.keys.get_mut(&account_id).ok_or(AuthError::KeyNotFound)?;
         let to_remove: Vec<_> = key_set.iter().cloned().collect();
+        let removed_count = to_remove.len() as u32;
+        let mut removed_keys = Vec::new();
         for key_info in to_remove {
             key_set.remove(&key_info);
+            removed_keys.push(key_info.public_key.clone());
             AuthEvent::KeyRemoved {
                 account_id: account_id.clone(),
                 public_key: format!("{:?}", key_info.public_key),
+                expiration_timestamp: key_info.expiration_timestamp,
+                is_multi_sig: key_info.is_multi_sig,
+                multi_sig_threshold: key_info.multi_sig_threshold,
             }.emit();
         }
 
+        for public_key in removed_keys {
+            self.deindex_key(&public_key, &account_id);
+        }
         self.keys.remove(&account_id);
         self.last_active_timestamps.remove(&account_id);
         self.registered_accounts.remove(&account_id);
 
+        AuthEvent::AccountDeregistered {
+            account_id: account_id.clone(),
+            last_active_timestamp,
+            removed_key_count: removed_count,
+        }.emit();
+
         Ok(())
     }
 
@@ -282,6 +593,12 @@ impl AuthContractState {
         inactive_accounts
     }
 
+    /// Looks up a still-pending action request, e.g. so a prospective approver
+    /// can inspect what they'd be signing off on before calling `approve_action`.
+    pub fn get_action_request(&self, request_id: u64) -> Option<ActionRequest> {
+        self.action_requests.get(&request_id).cloned()
+    }
+
     pub fn get_key_info(&self, account_id: &AccountId, public_key: &PublicKey) -> Option<KeyInfo> {
         self.keys
             .get(account_id)
@@ -298,4 +615,382 @@ impl AuthContractState {
         let end = (offset + limit) as usize;
         key_set.iter().skip(start).take(end - start).cloned().collect()
     }
-}
\ No newline at end of file
+
+    /// Resolves a registered `public_key` to the accounts that hold it, via
+    /// the maintained reverse index, instead of scanning `registered_accounts`.
+    pub fn get_accounts_by_key(&self, public_key: &PublicKey, limit: u32, offset: u32) -> Vec<AccountId> {
+        assert!(limit <= 100, "Limit exceeds maximum allowed value");
+        let accounts = match self.key_index.get(public_key) {
+            Some(set) => set,
+            None => return Vec::new(),
+        };
+        let start = offset as usize;
+        let end = (offset + limit) as usize;
+        accounts.iter().skip(start).take(end - start).cloned().collect()
+    }
+
+    /// Streams a deterministic, paginated slice of the full contract state for
+    /// off-chain indexers and migration tooling. Optionally LZ4-compresses the
+    /// borsh-serialized payload.
+    pub fn export_state(&self, limit: u32, offset: u32, compress: bool) -> ExportChunk {
+        assert!(limit <= 100, "Limit exceeds maximum allowed value");
+        let start = offset as usize;
+        let end = (offset + limit) as usize;
+
+        let snapshots: Vec<AccountSnapshot> = self.registered_accounts.iter()
+            .skip(start)
+            .take(end - start)
+            .map(|account_id| AccountSnapshot {
+                account_id: account_id.clone(),
+                keys: self.keys.get(account_id)
+                    .map(|set| set.iter().cloned().collect())
+                    .unwrap_or_default(),
+                last_active_timestamp: self.last_active_timestamps.get(account_id).copied().unwrap_or(0),
+            })
+            .collect();
+
+        let payload = borsh::to_vec(&snapshots).expect("snapshot payload should serialize");
+        if compress {
+            ExportChunk { compressed: true, data: lz4_flex::compress_prepend_size(&payload) }
+        } else {
+            ExportChunk { compressed: false, data: payload }
+        }
+    }
+
+    /// Restores accounts, keys, and the key index from a chunk produced by
+    /// `export_state`. Intended for bootstrapping a freshly migrated/redeployed
+    /// contract; for existing accounts, any keys they're missing are merged in
+    /// and the later of the two last-active timestamps is kept.
+    pub fn import_state(&mut self, caller: &AccountId, chunk: ExportChunk) -> Result<(), AuthError> {
+        self.require_admin(caller)?;
+
+        let payload = if chunk.compressed {
+            lz4_flex::decompress_size_prepended(&chunk.data).map_err(|_| AuthError::ImportFailed)?
+        } else {
+            chunk.data
+        };
+        let snapshots = Vec::<AccountSnapshot>::try_from_slice(&payload).map_err(|_| AuthError::ImportFailed)?;
+
+        for snapshot in snapshots {
+            if self.keys.get(&snapshot.account_id).is_none() {
+                self.keys.insert(snapshot.account_id.clone(), IterableSet::new(StorageKey::KeySet {
+                    account_id: snapshot.account_id.clone(),
+                }));
+            }
+            self.registered_accounts.insert(snapshot.account_id.clone());
+
+            let key_set = self.keys.get_mut(&snapshot.account_id).expect("key set should exist");
+            let mut newly_indexed = Vec::new();
+            for key_info in snapshot.keys {
+                if !key_set.contains(&key_info) {
+                    newly_indexed.push(key_info.public_key.clone());
+                    key_set.insert(key_info);
+                }
+            }
+
+            for public_key in newly_indexed {
+                self.index_key(&public_key, &snapshot.account_id);
+            }
+
+            // Never move a live account's activity clock backward — an older
+            // snapshot restored onto a newer contract must not make it look
+            // inactive again.
+            let merged_last_active = self.last_active_timestamps.get(&snapshot.account_id)
+                .copied()
+                .map_or(snapshot.last_active_timestamp, |existing| existing.max(snapshot.last_active_timestamp));
+            self.last_active_timestamps.insert(snapshot.account_id, merged_last_active);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+
+    fn set_predecessor(account_id: AccountId) {
+        let mut builder = VMContextBuilder::new();
+        builder.predecessor_account_id(account_id);
+        testing_env!(builder.build());
+    }
+
+    fn sample_key(tag: u8) -> PublicKey {
+        let mut bytes = vec![0u8; 33];
+        bytes[0] = 0; // ed25519 curve tag
+        bytes[1] = tag;
+        PublicKey::try_from(bytes).unwrap()
+    }
+
+    /// Deterministically derives an ed25519 signing key and its NEAR `PublicKey`
+    /// encoding, so tests can produce signatures `verify_ed25519` actually accepts.
+    fn signing_keypair(seed: u8) -> (ed25519_dalek::SigningKey, PublicKey) {
+        let signing_key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+        let mut bytes = vec![0u8; 33];
+        bytes[0] = 0; // ed25519 curve tag
+        bytes[1..].copy_from_slice(signing_key.verifying_key().as_bytes());
+        (signing_key, PublicKey::try_from(bytes).unwrap())
+    }
+
+    fn sign(signing_key: &ed25519_dalek::SigningKey, message: &[u8]) -> Vec<u8> {
+        use ed25519_dalek::Signer;
+        signing_key.sign(message).to_bytes().to_vec()
+    }
+
+    #[test]
+    fn rotate_key_deindexes_old_key_and_indexes_new_key() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let old_key = sample_key(1);
+        let new_key = sample_key(2);
+        state.register_key(&account, &account, old_key.clone(), None, false, None).unwrap();
+        assert_eq!(state.get_accounts_by_key(&old_key, 10, 0), vec![account.clone()]);
+
+        state.rotate_key(&account, &account, old_key.clone(), new_key.clone(), None, false, None).unwrap();
+
+        assert!(state.get_accounts_by_key(&old_key, 10, 0).is_empty());
+        assert_eq!(state.get_accounts_by_key(&new_key, 10, 0), vec![account]);
+    }
+
+    #[test]
+    fn shared_key_indexes_all_owning_accounts() {
+        let alice = accounts(0);
+        let bob = accounts(1);
+        let shared_key = sample_key(9);
+
+        set_predecessor(alice.clone());
+        let mut state = AuthContractState::new(alice.clone());
+        state.register_key(&alice, &alice, shared_key.clone(), None, false, None).unwrap();
+
+        set_predecessor(bob.clone());
+        state.register_key(&bob, &bob, shared_key.clone(), None, false, None).unwrap();
+
+        let mut owners = state.get_accounts_by_key(&shared_key, 10, 0);
+        owners.sort();
+        let mut expected = vec![alice.clone(), bob.clone()];
+        expected.sort();
+        assert_eq!(owners, expected);
+
+        state.remove_key(&bob, &bob, shared_key.clone()).unwrap();
+        assert_eq!(state.get_accounts_by_key(&shared_key, 10, 0), vec![alice]);
+    }
+
+    #[test]
+    fn is_authorized_accepts_enough_distinct_valid_signatures() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (signer_a, key_a) = signing_keypair(1);
+        let (signer_b, key_b) = signing_keypair(2);
+        state.register_key(&account, &account, key_a.clone(), None, true, Some(2)).unwrap();
+        state.register_key(&account, &account, key_b.clone(), None, true, Some(2)).unwrap();
+
+        let action = "transfer".to_string();
+        let message = borsh::to_vec(&(&account, 0u64, &action)).unwrap();
+        let signatures = vec![sign(&signer_a, &message), sign(&signer_b, &message)];
+
+        assert!(state.is_authorized(&account, &key_a, Some(signatures), action));
+    }
+
+    #[test]
+    fn is_authorized_rejects_forged_signature() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (_, key_a) = signing_keypair(1);
+        let (signer_b, key_b) = signing_keypair(2);
+        state.register_key(&account, &account, key_a.clone(), None, true, Some(2)).unwrap();
+        state.register_key(&account, &account, key_b.clone(), None, true, Some(2)).unwrap();
+
+        let action = "transfer".to_string();
+        let message = borsh::to_vec(&(&account, 0u64, &action)).unwrap();
+        // Only one real signature plus a garbage one pretending to be `key_a`'s.
+        let signatures = vec![sign(&signer_b, &message), vec![0u8; 64]];
+
+        assert!(!state.is_authorized(&account, &key_a, Some(signatures), action));
+    }
+
+    #[test]
+    fn is_authorized_counts_distinct_signers_not_signature_count() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (signer_a, key_a) = signing_keypair(1);
+        let (_, key_b) = signing_keypair(2);
+        state.register_key(&account, &account, key_a.clone(), None, true, Some(2)).unwrap();
+        state.register_key(&account, &account, key_b.clone(), None, true, Some(2)).unwrap();
+
+        let action = "transfer".to_string();
+        let message = borsh::to_vec(&(&account, 0u64, &action)).unwrap();
+        // Same signer's signature repeated should still only count once.
+        let signature = sign(&signer_a, &message);
+        let signatures = vec![signature.clone(), signature];
+
+        assert!(!state.is_authorized(&account, &key_a, Some(signatures), action));
+    }
+
+    #[test]
+    fn propose_approve_reaches_quorum_and_auto_executes() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (signer_a, key_a) = signing_keypair(1);
+        let (signer_b, key_b) = signing_keypair(2);
+        state.register_key(&account, &account, key_a.clone(), None, false, None).unwrap();
+        state.register_key(&account, &account, key_b.clone(), None, true, Some(2)).unwrap();
+
+        let request_id = state.propose_action(&account, &account, PendingAction::RemoveKey {
+            public_key: key_b.clone(),
+        }).unwrap();
+
+        let message = borsh::to_vec(&(&account, 0u64, request_id)).unwrap();
+        let first = state.approve_action(request_id, key_a.clone(), sign(&signer_a, &message)).unwrap();
+        assert!(!first, "a single approval shouldn't meet a threshold of 2");
+        assert!(state.get_action_request(request_id).is_some());
+
+        let second = state.approve_action(request_id, key_b.clone(), sign(&signer_b, &message)).unwrap();
+        assert!(second, "the second distinct approver should reach quorum and execute");
+
+        assert!(state.get_action_request(request_id).is_none());
+        assert!(state.get_key_info(&account, &key_b).is_none());
+    }
+
+    #[test]
+    fn approve_action_rejects_invalid_signature() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (_, key_a) = signing_keypair(1);
+        state.register_key(&account, &account, key_a.clone(), None, false, None).unwrap();
+
+        let request_id = state.propose_action(&account, &account, PendingAction::RemoveKey {
+            public_key: key_a.clone(),
+        }).unwrap();
+
+        let result = state.approve_action(request_id, key_a.clone(), vec![0u8; 64]);
+        assert!(matches!(result, Err(AuthError::InvalidSignature)));
+        assert!(state.get_action_request(request_id).is_some());
+    }
+
+    #[test]
+    fn approve_action_expires_and_purges_stale_request() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut state = AuthContractState::new(account.clone());
+
+        let (signer_a, key_a) = signing_keypair(1);
+        state.register_key(&account, &account, key_a.clone(), None, false, None).unwrap();
+
+        let request_id = state.propose_action(&account, &account, PendingAction::RemoveKey {
+            public_key: key_a.clone(),
+        }).unwrap();
+
+        let mut builder = VMContextBuilder::new();
+        builder.predecessor_account_id(account.clone());
+        builder.block_timestamp((REQUEST_EXPIRY_MS + 1) * 1_000_000);
+        testing_env!(builder.build());
+
+        let message = borsh::to_vec(&(&account, 0u64, request_id)).unwrap();
+        let result = state.approve_action(request_id, key_a.clone(), sign(&signer_a, &message));
+
+        assert!(matches!(result, Err(AuthError::RequestExpired)));
+        assert!(state.get_action_request(request_id).is_none());
+        assert_eq!(state.nonces.get(&account).copied().unwrap_or(0), 1);
+    }
+
+    #[test]
+    fn add_admin_rejects_duplicate_and_remove_admin_protects_last_admin() {
+        let owner = accounts(0);
+        let new_admin = accounts(1);
+        set_predecessor(owner.clone());
+        let mut state = AuthContractState::new(owner.clone());
+
+        state.add_admin(&owner, new_admin.clone()).unwrap();
+        assert!(matches!(
+            state.add_admin(&owner, new_admin.clone()),
+            Err(AuthError::AdminAlreadyExists)
+        ));
+
+        state.remove_admin(&owner, new_admin).unwrap();
+        assert!(matches!(
+            state.remove_admin(&owner, owner.clone()),
+            Err(AuthError::LastAdmin)
+        ));
+    }
+
+    #[test]
+    fn pause_blocks_every_mutating_entrypoint_and_unpause_restores_them() {
+        let owner = accounts(0);
+        set_predecessor(owner.clone());
+        let mut state = AuthContractState::new(owner.clone());
+        let key = sample_key(1);
+        let rotated_key = sample_key(2);
+
+        state.pause(&owner).unwrap();
+        assert!(matches!(
+            state.register_key(&owner, &owner, key.clone(), None, false, None),
+            Err(AuthError::ContractPaused)
+        ));
+
+        state.unpause(&owner).unwrap();
+        state.register_key(&owner, &owner, key.clone(), None, false, None).unwrap();
+
+        state.pause(&owner).unwrap();
+        assert!(matches!(
+            state.rotate_key(&owner, &owner, key.clone(), rotated_key.clone(), None, false, None),
+            Err(AuthError::ContractPaused)
+        ));
+        assert!(matches!(
+            state.remove_expired_keys(&owner),
+            Err(AuthError::ContractPaused)
+        ));
+        assert!(matches!(
+            state.remove_inactive_accounts(owner.clone()),
+            Err(AuthError::ContractPaused)
+        ));
+        assert!(matches!(
+            state.remove_key(&owner, &owner, key.clone()),
+            Err(AuthError::ContractPaused)
+        ));
+
+        state.unpause(&owner).unwrap();
+        state.rotate_key(&owner, &owner, key, rotated_key.clone(), None, false, None).unwrap();
+        state.remove_expired_keys(&owner).unwrap();
+        state.remove_key(&owner, &owner, rotated_key).unwrap();
+    }
+
+    #[test]
+    fn export_import_round_trip_preserves_keys_and_activity() {
+        let account = accounts(0);
+        set_predecessor(account.clone());
+        let mut source = AuthContractState::new(account.clone());
+        let key = sample_key(1);
+        source.register_key(&account, &account, key.clone(), None, false, None).unwrap();
+        let original_last_active = source.last_active_timestamps.get(&account).copied().unwrap();
+
+        let chunk = source.export_state(10, 0, true);
+        assert!(chunk.compressed);
+
+        let admin = accounts(1);
+        set_predecessor(admin.clone());
+        let mut target = AuthContractState::new(admin.clone());
+        target.import_state(&admin, chunk).unwrap();
+
+        let imported_key = target.get_key_info(&account, &key).expect("key should survive the round trip");
+        assert_eq!(imported_key.public_key, key);
+        assert_eq!(
+            target.last_active_timestamps.get(&account).copied(),
+            Some(original_last_active),
+        );
+        assert_eq!(target.get_accounts_by_key(&key, 10, 0), vec![account]);
+    }
+}
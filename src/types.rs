@@ -1,4 +1,4 @@
-use near_sdk::PublicKey;
+use near_sdk::{AccountId, PublicKey};
 use near_sdk::serde::{Serialize, Deserialize};
 use near_sdk::borsh::{self, BorshSerialize, BorshDeserialize};
 use near_sdk_macros::NearSchema;
@@ -19,4 +19,83 @@ pub struct KeyInfo {
     pub expiration_timestamp: Option<u64>,
     pub is_multi_sig: bool,
     pub multi_sig_threshold: Option<u32>,
+}
+
+/// A sensitive account action awaiting its target key's multisig threshold of approvals.
+#[derive(
+    NearSchema,
+    Serialize,
+    Deserialize,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize
+)]
+#[abi(json, borsh)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum PendingAction {
+    RotateKey {
+        old_public_key: PublicKey,
+        new_public_key: PublicKey,
+        expiration_days: Option<u32>,
+        is_multi_sig: bool,
+        multi_sig_threshold: Option<u32>,
+    },
+    RemoveKey {
+        public_key: PublicKey,
+    },
+}
+
+/// A pending `PendingAction` recorded while it collects distinct approver signatures.
+#[derive(
+    NearSchema,
+    Serialize,
+    Deserialize,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize
+)]
+#[abi(json, borsh)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ActionRequest {
+    pub account_id: AccountId,
+    pub action: PendingAction,
+    pub nonce: u64,
+    pub created_at: u64,
+}
+
+/// One account's worth of state as carried by an `ExportChunk`.
+#[derive(
+    NearSchema,
+    Serialize,
+    Deserialize,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize
+)]
+#[abi(json, borsh)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct AccountSnapshot {
+    pub account_id: AccountId,
+    pub keys: Vec<KeyInfo>,
+    pub last_active_timestamp: u64,
+}
+
+/// A page of `export_state`, borsh-serialized and optionally LZ4-compressed.
+#[derive(
+    NearSchema,
+    Serialize,
+    Deserialize,
+    Clone,
+    BorshSerialize,
+    BorshDeserialize
+)]
+#[abi(json, borsh)]
+#[serde(crate = "near_sdk::serde")]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct ExportChunk {
+    pub compressed: bool,
+    pub data: Vec<u8>,
 }
\ No newline at end of file